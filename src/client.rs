@@ -1,4 +1,6 @@
+use crate::container::{self, Container};
 use lazy_static::lazy_static;
+use shiplift::{builder::ContainerFilter, ContainerListOptions};
 use std::ops;
 use std::sync::Arc;
 
@@ -7,6 +9,14 @@ pub struct Client {
     inner_client: Arc<shiplift::Docker>,
 }
 
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("inner_client", &"shiplift::Docker")
+            .finish()
+    }
+}
+
 impl Client {
     /// Construct a new unique Docker Client. Unless you know you need
     /// a unique Client, you should probably `use Client::default()` which
@@ -16,6 +26,35 @@ impl Client {
             inner_client: Arc::new(shiplift::Docker::new()),
         }
     }
+
+    /// The underlying `shiplift` Docker client, shared by reference count.
+    pub(crate) fn docker(&self) -> Arc<shiplift::Docker> {
+        Arc::clone(&self.inner_client)
+    }
+
+    /// Discover containers that already exist on the Docker host.
+    ///
+    /// Returns a [`ContainersQuery`] builder - call
+    /// [`list`](ContainersQuery::list) to run it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use harbourmaster::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let containers = Client::default()
+    ///         .containers()
+    ///         .label("project", "harbourmaster")
+    ///         .list()
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[must_use]
+    pub fn containers(&self) -> ContainersQuery {
+        ContainersQuery::new(Client::from(self))
+    }
 }
 
 impl Default for Client {
@@ -81,3 +120,118 @@ fn global_client() -> Client {
     let r: &Client = &CLIENT;
     Client::from(r)
 }
+
+/// A fluent query for discovering containers that already exist on the
+/// Docker host.
+///
+/// See [`Client::containers`].
+#[derive(Debug)]
+pub struct ContainersQuery {
+    client: Client,
+    all: bool,
+    labels: Vec<(String, String)>,
+    statuses: Vec<String>,
+    names: Vec<String>,
+    health: Option<String>,
+}
+
+impl ContainersQuery {
+    fn new(client: Client) -> Self {
+        Self {
+            client,
+            all: true,
+            labels: Vec::new(),
+            statuses: Vec::new(),
+            names: Vec::new(),
+            health: None,
+        }
+    }
+
+    /// Only include currently-running containers, excluding stopped/exited
+    /// ones. By default all containers are included.
+    pub fn running_only(mut self) -> Self {
+        self.all = false;
+        self
+    }
+
+    /// Only include containers carrying the given label.
+    ///
+    /// Can be called multiple times to filter on multiple labels.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.push((key.into(), value.into()));
+        self
+    }
+
+    /// Only include containers whose name matches `name`.
+    ///
+    /// Can be called multiple times to match any of several names.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    /// Only include containers in the given status, e.g. `"running"` or
+    /// `"exited"`.
+    ///
+    /// Can be called multiple times to match any of several statuses.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.statuses.push(status.into());
+        self
+    }
+
+    /// Only include containers reporting the given Docker healthcheck
+    /// status, e.g. `"healthy"` or `"unhealthy"`.
+    pub fn health(mut self, health: impl Into<String>) -> Self {
+        self.health = Some(health.into());
+        self
+    }
+
+    /// Run the query, returning the matching containers.
+    pub async fn list(self) -> Result<Vec<Container>, shiplift::Error> {
+        let mut options = ContainerListOptions::builder();
+        if self.all {
+            options.all();
+        }
+
+        let mut filters: Vec<ContainerFilter> = self
+            .labels
+            .iter()
+            .map(|(key, value)| ContainerFilter::Label(key.clone(), value.clone()))
+            .collect();
+        filters.extend(self.statuses.iter().cloned().map(ContainerFilter::Status));
+        if !filters.is_empty() {
+            options.filter(filters);
+        }
+
+        let summaries = self.client.docker().containers().list(&options.build()).await?;
+
+        let mut containers = Vec::new();
+        for summary in summaries {
+            if !self.names.is_empty() && !self.names.iter().any(|name| {
+                summary
+                    .names
+                    .iter()
+                    .any(|found| found.trim_start_matches('/') == name)
+            }) {
+                continue;
+            }
+
+            let details = container::inspect_container(&self.client, &summary.id).await?;
+
+            if let Some(health) = &self.health {
+                let matches = details
+                    .state
+                    .health
+                    .as_ref()
+                    .map_or(false, |h| &h.status == health);
+                if !matches {
+                    continue;
+                }
+            }
+
+            containers.push(container::from_details(details, Client::from(&self.client)));
+        }
+
+        Ok(containers)
+    }
+}
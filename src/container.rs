@@ -1,5 +1,8 @@
+use crate::exec::{self, ExecChunk, ExecResult};
+use crate::network::NetworkRef;
+use crate::wait::{self, WaitFor};
 use crate::{Client, Protocol};
-use futures_util::stream::StreamExt;
+use futures_util::stream::{Stream, StreamExt};
 use log::{debug, info};
 use rand::{
     distributions::{Alphanumeric, Distribution},
@@ -9,7 +12,11 @@ use shiplift::{
     rep::{ContainerCreateInfo, ContainerDetails},
     ContainerOptions, PullOptions, RmContainerOptions,
 };
-use std::{collections::HashMap, net::SocketAddrV4};
+use std::{
+    collections::HashMap,
+    net::SocketAddrV4,
+    time::Duration,
+};
 
 struct Port {
     pub source: u32,
@@ -17,6 +24,42 @@ struct Port {
     pub protocol: Protocol,
 }
 
+enum Mount {
+    Volume {
+        name: String,
+        container_path: String,
+    },
+    Bind {
+        host_path: String,
+        container_path: String,
+        read_only: bool,
+    },
+}
+
+impl Mount {
+    /// Render this mount in the `docker run -v` spec format, e.g.
+    /// `my-volume:/data` or `/host/path:/data:ro`.
+    fn to_docker_spec(&self) -> String {
+        match self {
+            Mount::Volume {
+                name,
+                container_path,
+            } => format!("{}:{}", name, container_path),
+            Mount::Bind {
+                host_path,
+                container_path,
+                read_only,
+            } => {
+                if *read_only {
+                    format!("{}:{}:ro", host_path, container_path)
+                } else {
+                    format!("{}:{}", host_path, container_path)
+                }
+            }
+        }
+    }
+}
+
 /// Abstraction of a running Docker container.
 ///
 /// Use the [new](Container::new)
@@ -24,13 +67,52 @@ struct Port {
 /// [builder](Container::builder) method if you need advanced features.
 ///
 /// Container constructors return a future which will be resolved to a
-/// Container. The Containers will NOT clean themselves up when they are
-/// dropped, you must call the [delete](Container::delete) method on them to
-/// remove the container from the host machine.
+/// Container. By default Containers will NOT clean themselves up when they
+/// are dropped, you must call the [delete](Container::delete) method on them
+/// to remove the container from the host machine - unless the builder was
+/// configured with [`remove_on_drop`](ContainerBuilder::remove_on_drop).
 pub struct Container {
     pub(crate) details: ContainerDetails,
 
     client: Client,
+    remove_on_drop: bool,
+}
+
+impl Drop for Container {
+    fn drop(&mut self) {
+        if !self.remove_on_drop {
+            return;
+        }
+
+        let docker = self.client.docker();
+        let id = self.details.id.clone();
+
+        // `delete()` is async and `Drop` is not, and merely spawning the
+        // removal onto whatever runtime happens to be current isn't enough:
+        // if the caller's runtime is torn down (or the current-thread
+        // runtime driving a `#[tokio::test]` returns) immediately after this
+        // `Container` goes out of scope, a spawned-but-not-yet-polled task
+        // is simply cancelled and the container leaks. Run the removal on
+        // its own background thread, with its own runtime, so it outlives
+        // the caller and is guaranteed to run to completion - the same
+        // approach testcontainers-rs uses for its drop handler.
+        std::thread::spawn(move || {
+            let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+            else {
+                return;
+            };
+
+            runtime.block_on(async move {
+                let _ = docker
+                    .containers()
+                    .get(&id)
+                    .remove(RmContainerOptions::builder().force(true).build())
+                    .await;
+            });
+        });
+    }
 }
 
 impl Container {
@@ -126,16 +208,46 @@ impl Container {
         &self.details.id
     }
 
-    /// Not yet implemented
-    pub fn ports(&self) -> &HashMap<SourcePort, Vec<HostPort>> {
-        let _map = self
-            .details
-            .network_settings
-            .ports
-            .clone()
-            .unwrap_or_default();
+    /// The container's exposed ports, parsed into the host bindings they
+    /// were mapped to.
+    ///
+    /// Docker allows a single container port to be bound to more than one
+    /// host port/interface, hence the `Vec<HostPort>` value - in the common
+    /// case of a single binding, [`host_port`](Container::host_port) is more
+    /// convenient.
+    ///
+    /// [`host_port`](Container::host_port) only ever returns the first
+    /// binding it finds; use this method instead if a container port may be
+    /// bound to more than one host port and all of them are needed.
+    #[must_use]
+    pub fn ports(&self) -> HashMap<SourcePort, Vec<HostPort>> {
+        let raw = match &self.details.network_settings.ports {
+            Some(raw) => raw,
+            None => return HashMap::new(),
+        };
+
+        raw.iter()
+            .filter_map(|(key, bindings)| {
+                let source = parse_source_port(key)?;
+                let bindings = bindings
+                    .as_ref()?
+                    .iter()
+                    .filter_map(parse_host_port)
+                    .collect();
+                Some((source, bindings))
+            })
+            .collect()
+    }
 
-        todo!()
+    /// The host port mapped to a given container port.
+    ///
+    /// If the container port is bound to more than one host port, this
+    /// returns the first one Docker reports - use
+    /// [`ports`](Container::ports) directly if a container port may be bound
+    /// to more than one host port and all of them are needed.
+    #[must_use]
+    pub fn host_port(&self, source: u16, protocol: Protocol) -> Option<u16> {
+        find_host_port(&self.details, (source, protocol)).map(|addr| addr.port())
     }
 
     /// Exposes the underlying representation of the Docker container's ports.
@@ -144,11 +256,49 @@ impl Container {
         &self.details.network_settings.ports
     }
 
+    /// Run a command inside the container and wait for it to complete,
+    /// collecting its output.
+    ///
+    /// # Example
+    ///  ```no_run
+    /// use harbourmaster::Container;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let container = Container::new("alpine").await.unwrap();
+    ///
+    ///     let result = container.exec(vec!["echo", "hello"]).await.unwrap();
+    ///     assert_eq!(result.stdout.trim(), "hello");
+    ///
+    ///     container.delete().await.unwrap();
+    /// }
+    ///  ```
+    pub async fn exec(
+        &self,
+        cmd: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<ExecResult, shiplift::Error> {
+        let cmd = cmd.into_iter().map(Into::into).collect();
+        exec::exec(&self.client, self.id(), cmd).await
+    }
+
+    /// Run a command inside the container, streaming its stdout/stderr
+    /// chunks as they are produced rather than waiting for completion.
+    pub fn exec_stream(
+        &self,
+        cmd: impl IntoIterator<Item = impl Into<String>>,
+    ) -> impl Stream<Item = Result<ExecChunk, shiplift::Error>> + '_ {
+        let cmd = cmd.into_iter().map(Into::into).collect();
+        exec::exec_stream(&self.client, self.id().to_owned(), cmd)
+    }
+
     /// Delete the running docker container.
     ///
     /// This is equivalent to calling `docker rm -f [container]`.
-    pub async fn delete(self) -> Result<(), shiplift::Error> {
+    pub async fn delete(mut self) -> Result<(), shiplift::Error> {
+        self.remove_on_drop = false;
+
         self.client
+            .docker()
             .containers()
             .get(self.id())
             .remove(RmContainerOptions::builder().force(true).build())
@@ -159,6 +309,53 @@ impl Container {
 pub type SourcePort = (u16, Protocol);
 pub type HostPort = SocketAddrV4;
 
+/// Find the host-side binding for a single container port, as reported by
+/// `ContainerDetails::network_settings::ports`.
+///
+/// Docker keys this map by strings like `"5984/tcp"`, and each binding is a
+/// loose `HashMap` with `"HostIp"`/`"HostPort"` entries, so this is shared by
+/// [`Container::host_port`](Container::host_port) and the [`WaitFor::Port`](crate::WaitFor::Port)
+/// wait strategy.
+pub(crate) fn find_host_port(details: &ContainerDetails, source: SourcePort) -> Option<HostPort> {
+    let (port, protocol) = source;
+    let key = format!("{}/{}", port, protocol.as_ref());
+
+    let bindings = details
+        .network_settings
+        .ports
+        .as_ref()?
+        .get(&key)?
+        .as_ref()?;
+
+    bindings.iter().find_map(parse_host_port)
+}
+
+/// Parse a Docker port-map key, e.g. `"5984/tcp"`, into a [`SourcePort`].
+fn parse_source_port(key: &str) -> Option<SourcePort> {
+    let (port, protocol) = key.split_once('/')?;
+    let port = port.parse().ok()?;
+    let protocol = match protocol {
+        "tcp" => Protocol::Tcp,
+        "udp" => Protocol::Udp,
+        _ => return None,
+    };
+    Some((port, protocol))
+}
+
+/// Parse a single binding entry, e.g. `{"HostIp": "0.0.0.0", "HostPort":
+/// "32768"}`, into a [`HostPort`].
+fn parse_host_port(binding: &HashMap<String, String>) -> Option<HostPort> {
+    let host_port: u16 = binding.get("HostPort")?.parse().ok()?;
+    let host_ip = binding
+        .get("HostIp")
+        .filter(|ip| !ip.is_empty())
+        .map_or(std::net::Ipv4Addr::UNSPECIFIED, |ip| {
+            ip.parse().unwrap_or(std::net::Ipv4Addr::UNSPECIFIED)
+        });
+
+    Some(SocketAddrV4::new(host_ip, host_port))
+}
+
 /// Builder struct for fine control over the construction of a
 /// [Container](Container).
 ///
@@ -170,13 +367,25 @@ pub struct ContainerBuilder {
     ports: Vec<Port>,
     commands: Vec<String>,
     environment_variables: Vec<String>,
+    mounts: Vec<Mount>,
+    network: Option<NetworkRef>,
 
     client: Client,
 
     pull_on_build: bool,
     slug_length: usize,
+
+    wait_strategies: Vec<WaitFor>,
+    wait_timeout: Duration,
+
+    remove_on_drop: bool,
 }
 
+/// The default overall timeout applied to a [`ContainerBuilder`]'s wait
+/// strategies, if [`wait_timeout`](ContainerBuilder::wait_timeout) is not
+/// called.
+const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl ContainerBuilder {
     fn new(image_name: impl Into<String>) -> Self {
         ContainerBuilder {
@@ -186,11 +395,18 @@ impl ContainerBuilder {
             ports: Vec::new(),
             commands: Vec::new(),
             environment_variables: Vec::new(),
+            mounts: Vec::new(),
+            network: None,
 
             client: Client::default(),
 
             pull_on_build: false,
             slug_length: 0,
+
+            wait_strategies: Vec::new(),
+            wait_timeout: DEFAULT_WAIT_TIMEOUT,
+
+            remove_on_drop: false,
         }
     }
 
@@ -277,6 +493,45 @@ impl ContainerBuilder {
         self
     }
 
+    /// Mount a named Docker [`Volume`](crate::Volume) into the container at
+    /// `container_path`.
+    ///
+    /// Can be called multiple times to mount multiple volumes.
+    pub fn volume(mut self, name: impl Into<String>, container_path: impl Into<String>) -> Self {
+        self.mounts.push(Mount::Volume {
+            name: name.into(),
+            container_path: container_path.into(),
+        });
+        self
+    }
+
+    /// Bind-mount a path on the host machine into the container at
+    /// `container_path`.
+    ///
+    /// Can be called multiple times to add multiple bind mounts.
+    pub fn bind_mount(
+        mut self,
+        host_path: impl Into<String>,
+        container_path: impl Into<String>,
+        read_only: bool,
+    ) -> Self {
+        self.mounts.push(Mount::Bind {
+            host_path: host_path.into(),
+            container_path: container_path.into(),
+            read_only,
+        });
+        self
+    }
+
+    /// Attach the container to an existing Docker [`Network`](crate::Network)
+    /// at creation time.
+    ///
+    /// Accepts either a `&Network` or a bare network name/id.
+    pub fn network(mut self, network: impl Into<NetworkRef>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+
     /// Set whether the client will attempt to pull the image from the internet
     /// before running the container. defaults to false.
     pub fn pull_on_build(mut self, pull: bool) -> Self {
@@ -284,6 +539,36 @@ impl ContainerBuilder {
         self
     }
 
+    /// Add a readiness strategy that `build()` must resolve before it
+    /// returns the `Container`.
+    ///
+    /// Can be called multiple times; strategies are resolved in the order
+    /// they were added.
+    pub fn wait_for(mut self, strategy: WaitFor) -> Self {
+        self.wait_strategies.push(strategy);
+        self
+    }
+
+    /// Force-remove the container when it is dropped, instead of leaking it
+    /// until something calls [`delete`](Container::delete).
+    ///
+    /// Because [`delete`](Container::delete) is async and `Drop` is not,
+    /// removal on drop runs on a dedicated background thread with its own
+    /// short-lived Tokio runtime, whether or not a runtime is already
+    /// running. Useful for test code, so a panic mid-test doesn't leak the
+    /// container.
+    pub fn remove_on_drop(mut self) -> Self {
+        self.remove_on_drop = true;
+        self
+    }
+
+    /// Set the overall timeout for resolving this builder's wait
+    /// strategies. Defaults to 30 seconds.
+    pub fn wait_timeout(mut self, timeout: Duration) -> Self {
+        self.wait_timeout = timeout;
+        self
+    }
+
     /// Consume the ContainerBuilder and return a future which resolves to the
     /// Container (or an error!).
     pub async fn build(self) -> Result<Container, shiplift::Error> {
@@ -301,14 +586,27 @@ impl ContainerBuilder {
             self.ports,
             commands,
             self.environment_variables,
+            &self.mounts,
+            self.network.as_ref(),
         )
         .await?;
         let id = create_info.id;
         run_container(&self.client, &id).await?;
         let details = inspect_container(&self.client, &id).await?;
+
+        wait::resolve(
+            &self.client,
+            &id,
+            &details,
+            self.wait_strategies,
+            self.wait_timeout,
+        )
+        .await?;
+
         Ok(Container {
             details,
             client: self.client,
+            remove_on_drop: self.remove_on_drop,
         })
     }
 }
@@ -335,11 +633,17 @@ async fn create_container<S: AsRef<str>>(
     ports: impl IntoIterator<Item = Port>,
     commands: Vec<&str>,
     environment_variables: Vec<String>,
+    mounts: &[Mount],
+    network: Option<&NetworkRef>,
 ) -> Result<ContainerCreateInfo, shiplift::Error> {
     let mut container_options = ContainerOptions::builder(image);
     container_options.cmd(commands);
     container_options.env(environment_variables);
 
+    if let Some(network) = network {
+        container_options.network_mode(network.as_str());
+    }
+
     if let Some(name) = container_name.as_ref() {
         container_options.name(name.as_ref());
     }
@@ -348,13 +652,145 @@ async fn create_container<S: AsRef<str>>(
         container_options.expose(port.source, port.protocol.as_ref(), port.host);
     }
 
-    client.containers().create(&container_options.build()).await
+    let volume_specs: Vec<String> = mounts.iter().map(Mount::to_docker_spec).collect();
+    if !volume_specs.is_empty() {
+        container_options.volumes(volume_specs.iter().map(String::as_str).collect());
+    }
+
+    client
+        .docker()
+        .containers()
+        .create(&container_options.build())
+        .await
 }
 
 async fn run_container(client: &Client, id: &str) -> Result<(), shiplift::Error> {
-    client.containers().get(id).start().await
+    client.docker().containers().get(id).start().await
 }
 
-async fn inspect_container(client: &Client, id: &str) -> Result<ContainerDetails, shiplift::Error> {
-    client.containers().get(id).inspect().await
+pub(crate) async fn inspect_container(
+    client: &Client,
+    id: &str,
+) -> Result<ContainerDetails, shiplift::Error> {
+    client.docker().containers().get(id).inspect().await
+}
+
+/// Wrap already-fetched container details in a [`Container`], without
+/// creating or starting anything.
+///
+/// Used by [`Client::containers`](crate::Client::containers) to
+/// adopt containers that already exist on the host.
+pub(crate) fn from_details(details: ContainerDetails, client: Client) -> Container {
+    Container {
+        details,
+        client,
+        remove_on_drop: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_host_port, parse_source_port, Mount};
+    use crate::Protocol;
+    use std::collections::HashMap;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn volume_mount_formats_as_name_colon_path() {
+        let mount = Mount::Volume {
+            name: "my-volume".to_owned(),
+            container_path: "/data".to_owned(),
+        };
+        assert_eq!(mount.to_docker_spec(), "my-volume:/data");
+    }
+
+    #[test]
+    fn read_write_bind_mount_has_no_ro_suffix() {
+        let mount = Mount::Bind {
+            host_path: "/host/data".to_owned(),
+            container_path: "/data".to_owned(),
+            read_only: false,
+        };
+        assert_eq!(mount.to_docker_spec(), "/host/data:/data");
+    }
+
+    #[test]
+    fn read_only_bind_mount_appends_ro_suffix() {
+        let mount = Mount::Bind {
+            host_path: "/host/data".to_owned(),
+            container_path: "/data".to_owned(),
+            read_only: true,
+        };
+        assert_eq!(mount.to_docker_spec(), "/host/data:/data:ro");
+    }
+
+    #[test]
+    fn parse_source_port_accepts_tcp() {
+        assert_eq!(parse_source_port("5984/tcp"), Some((5984, Protocol::Tcp)));
+    }
+
+    #[test]
+    fn parse_source_port_accepts_udp() {
+        assert_eq!(parse_source_port("53/udp"), Some((53, Protocol::Udp)));
+    }
+
+    #[test]
+    fn parse_source_port_rejects_unknown_protocol() {
+        assert_eq!(parse_source_port("5984/sctp"), None);
+    }
+
+    #[test]
+    fn parse_source_port_rejects_missing_protocol() {
+        assert_eq!(parse_source_port("5984"), None);
+    }
+
+    #[test]
+    fn parse_source_port_rejects_non_numeric_port() {
+        assert_eq!(parse_source_port("abc/tcp"), None);
+    }
+
+    #[test]
+    fn parse_host_port_uses_host_ip_when_present() {
+        let binding = HashMap::from([
+            ("HostIp".to_owned(), "127.0.0.1".to_owned()),
+            ("HostPort".to_owned(), "32768".to_owned()),
+        ]);
+        assert_eq!(
+            parse_host_port(&binding),
+            Some(std::net::SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 32768))
+        );
+    }
+
+    #[test]
+    fn parse_host_port_defaults_to_unspecified_when_host_ip_missing() {
+        let binding = HashMap::from([("HostPort".to_owned(), "32768".to_owned())]);
+        assert_eq!(
+            parse_host_port(&binding),
+            Some(std::net::SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 32768))
+        );
+    }
+
+    #[test]
+    fn parse_host_port_defaults_to_unspecified_when_host_ip_empty() {
+        let binding = HashMap::from([
+            ("HostIp".to_owned(), String::new()),
+            ("HostPort".to_owned(), "32768".to_owned()),
+        ]);
+        assert_eq!(
+            parse_host_port(&binding),
+            Some(std::net::SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 32768))
+        );
+    }
+
+    #[test]
+    fn parse_host_port_rejects_missing_host_port() {
+        let binding = HashMap::new();
+        assert_eq!(parse_host_port(&binding), None);
+    }
+
+    #[test]
+    fn parse_host_port_rejects_non_numeric_host_port() {
+        let binding = HashMap::from([("HostPort".to_owned(), "not-a-port".to_owned())]);
+        assert_eq!(parse_host_port(&binding), None);
+    }
 }
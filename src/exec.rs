@@ -0,0 +1,89 @@
+use async_stream::try_stream;
+use futures_util::stream::{Stream, StreamExt};
+use shiplift::{tty::TtyChunk, Exec, ExecContainerOptions};
+
+use crate::Client;
+
+/// The outcome of a command run to completion via
+/// [`Container::exec`](crate::Container::exec).
+#[derive(Debug, Clone)]
+pub struct ExecResult {
+    /// The command's exit code, if Docker reported one.
+    pub exit_code: Option<i64>,
+
+    /// Everything the command wrote to stdout.
+    pub stdout: String,
+
+    /// Everything the command wrote to stderr.
+    pub stderr: String,
+}
+
+/// A single demultiplexed chunk of output from a running `exec` instance, as
+/// streamed by [`Container::exec_stream`](crate::Container::exec_stream).
+#[derive(Debug, Clone)]
+pub enum ExecChunk {
+    /// A chunk of stdout.
+    Stdout(Vec<u8>),
+    /// A chunk of stderr.
+    Stderr(Vec<u8>),
+}
+
+fn exec_options(cmd: &[String]) -> ExecContainerOptions {
+    let cmd: Vec<&str> = cmd.iter().map(String::as_str).collect();
+    ExecContainerOptions::builder()
+        .cmd(cmd)
+        .attach_stdout(true)
+        .attach_stderr(true)
+        .build()
+}
+
+pub(crate) async fn exec(
+    client: &Client,
+    id: &str,
+    cmd: Vec<String>,
+) -> Result<ExecResult, shiplift::Error> {
+    let exec = Exec::create(client, id, &exec_options(&cmd)).await?;
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    let mut chunks = exec.start();
+    while let Some(chunk) = chunks.next().await {
+        match chunk? {
+            // Docker's exec API doesn't write to stdin itself; treat this
+            // variant the same as stdout, matching `exec_stream`.
+            TtyChunk::StdOut(bytes) | TtyChunk::StdIn(bytes) => {
+                stdout.push_str(&String::from_utf8_lossy(&bytes));
+            }
+            TtyChunk::StdErr(bytes) => stderr.push_str(&String::from_utf8_lossy(&bytes)),
+        }
+    }
+    drop(chunks);
+
+    let details = exec.inspect().await?;
+
+    Ok(ExecResult {
+        exit_code: details.exit_code,
+        stdout,
+        stderr,
+    })
+}
+
+pub(crate) fn exec_stream<'docker>(
+    client: &'docker Client,
+    id: String,
+    cmd: Vec<String>,
+) -> impl Stream<Item = Result<ExecChunk, shiplift::Error>> + 'docker {
+    try_stream! {
+        let exec = Exec::create(client, &id, &exec_options(&cmd)).await?;
+        let mut chunks = exec.start();
+
+        while let Some(chunk) = chunks.next().await {
+            yield match chunk? {
+                TtyChunk::StdOut(bytes) => ExecChunk::Stdout(bytes),
+                TtyChunk::StdErr(bytes) => ExecChunk::Stderr(bytes),
+                TtyChunk::StdIn(bytes) => ExecChunk::Stdout(bytes),
+            };
+        }
+    }
+}
@@ -32,12 +32,18 @@
 //! ```
 
 mod client;
-pub use client::Client;
+pub use client::{Client, ContainersQuery};
 mod container;
+mod exec;
+pub use crate::exec::{ExecChunk, ExecResult};
 mod network;
-pub use crate::network::Network;
+pub use crate::network::{Network, NetworkRef};
 mod port;
 pub use crate::port::Protocol;
+mod volume;
+pub use crate::volume::Volume;
+mod wait;
+pub use crate::wait::{LogStream, WaitFor};
 pub use container::{Builder as ContainerBuilder, Container};
 
 pub use shiplift::Error;
@@ -1,7 +1,9 @@
 use std::fmt::Debug;
 
-use crate::Client;
-use shiplift::{builder::NetworkCreateOptionsBuilder, NetworkCreateOptions};
+use crate::{Client, Container};
+use shiplift::{
+    builder::NetworkCreateOptionsBuilder, ContainerConnectionOptions, NetworkCreateOptions,
+};
 
 /// Abstraction of a Docker network
 #[derive(Debug, Clone)]
@@ -27,12 +29,76 @@ impl Network {
         &self.id
     }
 
+    /// Attach an already-running container to this network.
+    ///
+    /// To attach a container at creation time instead, use
+    /// [`ContainerBuilder::network`](crate::ContainerBuilder::network).
+    pub async fn connect(&self, container: &Container) -> Result<(), shiplift::Error> {
+        let options = ContainerConnectionOptions::builder(container.id()).build();
+        self.client.networks().get(&self.id).connect(&options).await
+    }
+
+    /// Attach an already-running container to this network, with the given
+    /// network aliases so other containers on the network can reach it by
+    /// hostname.
+    pub async fn connect_with_aliases(
+        &self,
+        container: &Container,
+        aliases: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<(), shiplift::Error> {
+        let options = ContainerConnectionOptions::builder(container.id())
+            .aliases(aliases.into_iter().map(Into::into).collect())
+            .build();
+        self.client.networks().get(&self.id).connect(&options).await
+    }
+
+    /// Detach a container from this network.
+    pub async fn disconnect(&self, container: &Container) -> Result<(), shiplift::Error> {
+        let options = ContainerConnectionOptions::builder(container.id()).build();
+        self.client
+            .networks()
+            .get(&self.id)
+            .disconnect(&options)
+            .await
+    }
+
     /// Remove the Docker network
     pub async fn delete(self) -> Result<(), shiplift::Error> {
         self.client.networks().get(&self.id).delete().await
     }
 }
 
+/// A reference to a Docker network, by id or name.
+///
+/// Accepted by [`ContainerBuilder::network`](crate::ContainerBuilder::network) -
+/// either a [`&Network`](Network) or a bare network name/id.
+#[derive(Debug, Clone)]
+pub struct NetworkRef(String);
+
+impl NetworkRef {
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&Network> for NetworkRef {
+    fn from(network: &Network) -> Self {
+        NetworkRef(network.id().to_owned())
+    }
+}
+
+impl From<&str> for NetworkRef {
+    fn from(name: &str) -> Self {
+        NetworkRef(name.to_owned())
+    }
+}
+
+impl From<String> for NetworkRef {
+    fn from(name: String) -> Self {
+        NetworkRef(name)
+    }
+}
+
 pub struct Builder {
     client: Client,
     options: NetworkCreateOptionsBuilder,
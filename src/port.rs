@@ -1,5 +1,5 @@
 /// Enum representing a port's communication protocol
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Protocol {
     /// TCP protocol
     Tcp,
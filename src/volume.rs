@@ -0,0 +1,66 @@
+use std::fmt::Debug;
+
+use crate::Client;
+use shiplift::{builder::VolumeCreateOptionsBuilder, VolumeCreateOptions};
+
+/// Abstraction of a Docker volume
+#[derive(Debug, Clone)]
+pub struct Volume {
+    id: String,
+    client: Client,
+}
+
+impl Volume {
+    /// Return a Future which resolves to a new Volume.
+    pub async fn new(name: impl AsRef<str>) -> Result<Self, shiplift::Error> {
+        Builder::new(name).build().await
+    }
+
+    /// Create a volume using advanced configuration
+    pub fn builder(name: impl AsRef<str>) -> Builder {
+        Builder::new(name)
+    }
+
+    /// The unique id of the Docker volume
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Remove the Docker volume
+    pub async fn delete(self) -> Result<(), shiplift::Error> {
+        self.client.volumes().get(&self.id).delete().await
+    }
+}
+
+pub struct Builder {
+    client: Client,
+    options: VolumeCreateOptionsBuilder,
+}
+
+impl std::fmt::Debug for Builder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Builder")
+            .field("client", &self.client)
+            .field("options", &"VolumeCreateOptionsBuilder")
+            .finish()
+    }
+}
+
+impl Builder {
+    fn new(name: impl AsRef<str>) -> Self {
+        Self {
+            client: Client::default(),
+            options: VolumeCreateOptions::builder(name.as_ref()),
+        }
+    }
+
+    pub async fn build(self) -> Result<Volume, shiplift::Error> {
+        let create_info = self.client.volumes().create(&self.options.build()).await?;
+
+        Ok(Volume {
+            id: create_info.name,
+            client: self.client,
+        })
+    }
+}
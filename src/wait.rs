@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use futures_util::stream::StreamExt;
+use regex::Regex;
+use shiplift::{rep::ContainerDetails, tty::TtyChunk, LogsOptions};
+
+use crate::container::{find_host_port, SourcePort};
+use crate::Client;
+
+/// A strategy for deciding when a container is ready to be used.
+///
+/// Configure one or more of these on a
+/// [`ContainerBuilder`](crate::ContainerBuilder) via
+/// [`wait_for`](crate::ContainerBuilder::wait_for). They are resolved, in the
+/// order they were added, before
+/// [`build`](crate::ContainerBuilder::build) returns its `Container`.
+#[derive(Debug, Clone)]
+pub enum WaitFor {
+    /// Wait until a line matching `pattern` appears on the given log
+    /// stream(s).
+    LogMessage {
+        /// A regular expression matched against each complete log line (a
+        /// plain substring is also a valid regex, so simple cases need no
+        /// special syntax).
+        pattern: String,
+        /// Which of the container's log streams to search.
+        stream: LogStream,
+    },
+
+    /// Wait until Docker's own healthcheck reports the container as
+    /// `healthy`.
+    ///
+    /// The image must define a `HEALTHCHECK`, otherwise this will never
+    /// resolve and the overall wait will time out.
+    HealthCheck,
+
+    /// Wait until the host port mapped to the given container port accepts
+    /// TCP connections.
+    Port(SourcePort),
+}
+
+/// Which of a container's log streams to search for a
+/// [`WaitFor::LogMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    /// Search stdout only.
+    Stdout,
+    /// Search stderr only.
+    Stderr,
+    /// Search both stdout and stderr.
+    Both,
+}
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Resolve every configured wait strategy, in order, within the given
+/// overall timeout.
+pub(crate) async fn resolve(
+    client: &Client,
+    id: &str,
+    details: &ContainerDetails,
+    strategies: Vec<WaitFor>,
+    wait_timeout: Duration,
+) -> Result<(), shiplift::Error> {
+    let resolve_all = async {
+        for strategy in strategies {
+            apply(client, id, details, &strategy).await?;
+        }
+        Ok(())
+    };
+
+    match tokio::time::timeout(wait_timeout, resolve_all).await {
+        Ok(result) => result,
+        Err(_) => Err(timed_out()),
+    }
+}
+
+async fn apply(
+    client: &Client,
+    id: &str,
+    details: &ContainerDetails,
+    strategy: &WaitFor,
+) -> Result<(), shiplift::Error> {
+    match strategy {
+        WaitFor::LogMessage { pattern, stream } => {
+            wait_for_log_message(client, id, pattern, *stream).await
+        }
+        WaitFor::HealthCheck => wait_for_healthy(client, id).await,
+        WaitFor::Port(source) => wait_for_port(details, *source).await,
+    }
+}
+
+async fn wait_for_log_message(
+    client: &Client,
+    id: &str,
+    pattern: &str,
+    stream: LogStream,
+) -> Result<(), shiplift::Error> {
+    let matcher = Regex::new(pattern).map_err(invalid_pattern)?;
+
+    let mut options = LogsOptions::builder();
+    options.follow(true);
+    match stream {
+        LogStream::Stdout => {
+            options.stdout(true).stderr(false);
+        }
+        LogStream::Stderr => {
+            options.stdout(false).stderr(true);
+        }
+        LogStream::Both => {
+            options.stdout(true).stderr(true);
+        }
+    }
+
+    let mut logs = client.docker().containers().get(id).logs(&options.build());
+
+    // Log chunks don't align with line boundaries, so lines have to be
+    // reassembled from the raw byte chunks before matching against them.
+    let mut buffer = String::new();
+    while let Some(chunk) = logs.next().await {
+        let chunk = chunk?;
+        let bytes: &[u8] = match &chunk {
+            TtyChunk::StdOut(bytes) | TtyChunk::StdErr(bytes) | TtyChunk::StdIn(bytes) => bytes,
+        };
+        buffer.push_str(&String::from_utf8_lossy(bytes));
+
+        while let Some(newline) = buffer.find('\n') {
+            let line = buffer[..newline].to_owned();
+            buffer.drain(..=newline);
+
+            if matcher.is_match(&line) {
+                return Ok(());
+            }
+        }
+    }
+
+    // The stream closed - check whatever trailing, unterminated line is
+    // left in the buffer before giving up.
+    if matcher.is_match(&buffer) {
+        return Ok(());
+    }
+
+    Err(log_stream_ended())
+}
+
+async fn wait_for_healthy(client: &Client, id: &str) -> Result<(), shiplift::Error> {
+    loop {
+        let details = client.docker().containers().get(id).inspect().await?;
+
+        let healthy = details
+            .state
+            .health
+            .as_ref()
+            .map_or(false, |health| health.status == "healthy");
+
+        if healthy {
+            return Ok(());
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+const CONNECT_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(2);
+
+async fn wait_for_port(
+    details: &ContainerDetails,
+    source: SourcePort,
+) -> Result<(), shiplift::Error> {
+    loop {
+        if let Some(host_port) = find_host_port(details, source) {
+            // A blocking std TcpStream::connect would tie up the worker
+            // thread running this future for however long the OS-level
+            // connect attempt takes, which isn't bounded by POLL_INTERVAL -
+            // a filtered port can hang for many seconds and starve other
+            // tasks on the same thread. Use tokio's non-blocking connect,
+            // with its own short timeout per attempt.
+            let connected = tokio::time::timeout(
+                CONNECT_ATTEMPT_TIMEOUT,
+                tokio::net::TcpStream::connect(host_port),
+            )
+            .await;
+
+            if matches!(connected, Ok(Ok(_))) {
+                return Ok(());
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+fn timed_out() -> shiplift::Error {
+    shiplift::Error::Io(std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "timed out waiting for container to become ready",
+    ))
+}
+
+fn log_stream_ended() -> shiplift::Error {
+    shiplift::Error::Io(std::io::Error::new(
+        std::io::ErrorKind::UnexpectedEof,
+        "log stream ended before the expected pattern was observed",
+    ))
+}
+
+fn invalid_pattern(error: regex::Error) -> shiplift::Error {
+    shiplift::Error::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        error,
+    ))
+}